@@ -0,0 +1,192 @@
+use crate::{Pos, I};
+
+/// The set of maximal (non-dominated) positions under the Pareto order on
+/// `Pos`: `p` dominates `q` when `q <= p`.
+///
+/// This is the key structure for pruning redundant states in seed-heuristic
+/// and A*-style alignment, and for representing a wavefront as the skyline
+/// of positions reached so far. Positions are kept sorted by `i` ascending.
+///
+/// Equal points collapse to one, and inserting into an empty frontier always
+/// succeeds.
+///
+/// A frontier built by [`Frontier::minimal`] holds minimal points instead;
+/// `dominates` and `insert` always apply the maximal rule described here.
+#[derive(Debug, Clone, Default)]
+pub struct Frontier {
+    /// Maximal points, sorted by `i` ascending. Since no point dominates
+    /// another, `j` is strictly decreasing as `i` increases.
+    points: Vec<Pos>,
+}
+
+impl Frontier {
+    /// An empty frontier.
+    pub fn new() -> Self {
+        Self { points: Vec::new() }
+    }
+
+    /// Build the frontier of maximal points from a batch of positions.
+    ///
+    /// Sorts by `i` descending (ties by `j` descending), then sweeps left to
+    /// right keeping a point iff its `j` is strictly greater than the
+    /// running maximum `j` seen so far. This is the classic O(n log n)
+    /// skyline construction.
+    pub fn maximal(points: impl IntoIterator<Item = Pos>) -> Self {
+        let mut points: Vec<Pos> = points.into_iter().collect();
+        points.sort_unstable_by(|a, b| (b.0, b.1).cmp(&(a.0, a.1)));
+
+        let mut frontier = Vec::new();
+        let mut max_j: Option<I> = None;
+        for p in points {
+            if max_j.map_or(true, |max_j| p.1 > max_j) {
+                max_j = Some(p.1);
+                frontier.push(p);
+            }
+        }
+        // `frontier` was built in order of decreasing `i`; reverse to get
+        // increasing `i`.
+        frontier.reverse();
+        Self { points: frontier }
+    }
+
+    /// Build the frontier of minimal points (the lower-left skyline) from a
+    /// batch of positions. Mirrors `maximal` with the comparisons reversed.
+    ///
+    /// Only the construction is mirrored: `dominates` and `insert` on the
+    /// result still test and keep maximal points, so they do not maintain
+    /// the lower-left skyline.
+    pub fn minimal(points: impl IntoIterator<Item = Pos>) -> Self {
+        let mut points: Vec<Pos> = points.into_iter().collect();
+        points.sort_unstable_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+
+        let mut frontier = Vec::new();
+        let mut min_j: Option<I> = None;
+        for p in points {
+            if min_j.map_or(true, |min_j| p.1 < min_j) {
+                min_j = Some(p.1);
+                frontier.push(p);
+            }
+        }
+        Self { points: frontier }
+    }
+
+    /// Whether `p` is dominated by some point currently on the frontier
+    /// (including being equal to one).
+    pub fn dominates(&self, p: Pos) -> bool {
+        // `i` is sorted ascending, so the first point with `i >= p.0` is the
+        // only candidate that could dominate `p`: any point before it has a
+        // larger `j` (since the frontier is a decreasing staircase) but too
+        // small an `i`, and any point after it has too small a `j`.
+        let idx = self.points.partition_point(|q| q.0 < p.0);
+        self.points[idx..]
+            .first()
+            .is_some_and(|q| p.0 <= q.0 && p.1 <= q.1)
+    }
+
+    /// Insert `p`, keeping only maximal elements. Returns `true` if `p` was
+    /// non-dominated and added to the frontier (evicting any points that `p`
+    /// now dominates), or `false` if `p` was itself dominated.
+    pub fn insert(&mut self, p: Pos) -> bool {
+        if self.dominates(p) {
+            return false;
+        }
+
+        // All points with `i <= p.0` and `j <= p.1` are now dominated by
+        // `p` and must be evicted. Since the frontier is a decreasing
+        // staircase in `j` as `i` increases, both conditions carve out
+        // contiguous ranges: `q.0 <= p.0` is a prefix, and `q.1 <= p.1` is a
+        // suffix, so their intersection is a single contiguous run.
+        let prefix_end = self.points.partition_point(|q| q.0 <= p.0);
+        let suffix_start = self.points.partition_point(|q| q.1 > p.1).min(prefix_end);
+        self.points.splice(suffix_start..prefix_end, [p]);
+        true
+    }
+
+    /// Iterate over the current skyline, in order of increasing `i`.
+    pub fn iter(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.points.iter().copied()
+    }
+
+    /// The number of points on the frontier.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Whether the frontier has no points.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maximal_keeps_only_the_skyline() {
+        let f = Frontier::maximal(vec![Pos(0, 3), Pos(1, 2), Pos(2, 2), Pos(3, 0), Pos(1, 1)]);
+        assert_eq!(f.iter().collect::<Vec<_>>(), vec![Pos(0, 3), Pos(2, 2), Pos(3, 0)]);
+    }
+
+    #[test]
+    fn maximal_collapses_equal_points() {
+        let f = Frontier::maximal(vec![Pos(1, 1), Pos(1, 1)]);
+        assert_eq!(f.iter().collect::<Vec<_>>(), vec![Pos(1, 1)]);
+    }
+
+    #[test]
+    fn maximal_singleton_at_i32_min_is_kept() {
+        let f = Frontier::maximal(vec![Pos(5, i32::MIN)]);
+        assert_eq!(f.iter().collect::<Vec<_>>(), vec![Pos(5, i32::MIN)]);
+    }
+
+    #[test]
+    fn minimal_keeps_only_the_lower_left_skyline() {
+        let f = Frontier::minimal(vec![Pos(0, 0), Pos(1, 1), Pos(2, -1), Pos(3, 2)]);
+        assert_eq!(f.iter().collect::<Vec<_>>(), vec![Pos(0, 0), Pos(2, -1)]);
+    }
+
+    #[test]
+    fn minimal_singleton_at_i32_max_is_kept() {
+        let f = Frontier::minimal(vec![Pos(5, i32::MAX)]);
+        assert_eq!(f.iter().collect::<Vec<_>>(), vec![Pos(5, i32::MAX)]);
+    }
+
+    #[test]
+    fn empty_frontier_dominates_nothing_and_accepts_any_insert() {
+        let mut f = Frontier::new();
+        assert!(!f.dominates(Pos(0, 0)));
+        assert!(f.insert(Pos(2, 2)));
+        assert_eq!(f.len(), 1);
+    }
+
+    #[test]
+    fn insert_rejects_dominated_points() {
+        let mut f = Frontier::maximal(vec![Pos(2, 2)]);
+        assert!(!f.insert(Pos(1, 1)));
+        assert!(!f.insert(Pos(2, 2)));
+        assert_eq!(f.iter().collect::<Vec<_>>(), vec![Pos(2, 2)]);
+    }
+
+    #[test]
+    fn insert_evicts_newly_dominated_points() {
+        let mut f = Frontier::maximal(vec![Pos(0, 3), Pos(2, 2), Pos(3, 0)]);
+        assert!(f.insert(Pos(1, 4)));
+        assert_eq!(
+            f.iter().collect::<Vec<_>>(),
+            vec![Pos(1, 4), Pos(2, 2), Pos(3, 0)]
+        );
+
+        // A point dominating everything evicts the whole frontier.
+        assert!(f.insert(Pos(4, 4)));
+        assert_eq!(f.iter().collect::<Vec<_>>(), vec![Pos(4, 4)]);
+    }
+
+    #[test]
+    fn dominates_reports_equal_points_as_dominated() {
+        let f = Frontier::maximal(vec![Pos(1, 1)]);
+        assert!(f.dominates(Pos(1, 1)));
+        assert!(f.dominates(Pos(0, 0)));
+        assert!(!f.dominates(Pos(2, 2)));
+    }
+}
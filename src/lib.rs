@@ -1,10 +1,14 @@
 pub mod cigar;
 pub mod cost;
+pub mod frontier;
+pub mod order;
 
 use std::cmp::Ordering;
 
 pub use cigar::*;
 pub use cost::*;
+pub use frontier::*;
+pub use order::*;
 
 /// A single base
 // NOTE: This is also part of rust-bio-types.
@@ -64,10 +68,25 @@ impl PartialOrd for Pos {
         None
     }
 
+    #[inline]
+    fn lt(&self, other: &Self) -> bool {
+        self.le(other) && (self.0 < other.0 || self.1 < other.1)
+    }
+
     #[inline]
     fn le(&self, other: &Self) -> bool {
         self.0 <= other.0 && self.1 <= other.1
     }
+
+    #[inline]
+    fn gt(&self, other: &Self) -> bool {
+        self.ge(other) && (self.0 > other.0 || self.1 > other.1)
+    }
+
+    #[inline]
+    fn ge(&self, other: &Self) -> bool {
+        self.0 >= other.0 && self.1 >= other.1
+    }
 }
 
 /// The path corresponding to an alignment of two sequences.
@@ -107,4 +126,70 @@ impl Pos {
     {
         Pos(i.try_into().unwrap(), j.try_into().unwrap())
     }
+
+    /// Pack `(i, j)` into a single `i64`, `i` in the high bits and `j` in the
+    /// low bits. `j`'s sign bit is flipped so that the low word compares
+    /// correctly as part of an unsigned-style integer comparison even when
+    /// `j` is negative.
+    ///
+    /// Comparing packed keys as plain integers agrees with lexicographic
+    /// `(i, j)` order, which makes this a cheap totally-ordered key for
+    /// sorting large `Path`/`Vec<Pos>` buffers or for use as a dense
+    /// hash/index key.
+    #[inline]
+    pub fn to_packed(&self) -> i64 {
+        (self.0 as i64) << 32 | (self.1 as u32 ^ 0x8000_0000) as i64
+    }
+
+    /// Inverse of [`Pos::to_packed`].
+    #[inline]
+    pub fn from_packed(packed: i64) -> Self {
+        Pos((packed >> 32) as I, (packed as u32 ^ 0x8000_0000) as I)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pos_lt_gt_match_strict_dominance() {
+        assert!(Pos(1, 1) < Pos(2, 2));
+        assert!(Pos(1, 2) < Pos(1, 3));
+        assert!(Pos(2, 2) > Pos(1, 1));
+        assert!(!(Pos(1, 2) < Pos(2, 1)));
+        assert!(!(Pos(1, 2) > Pos(2, 1)));
+        assert!(!(Pos(1, 1) < Pos(1, 1)));
+        assert!(!(Pos(1, 1) > Pos(1, 1)));
+    }
+
+    #[test]
+    fn pos_le_ge_agree_with_lt_gt_on_equality() {
+        assert!(Pos(1, 1) <= Pos(1, 1));
+        assert!(Pos(1, 1) >= Pos(1, 1));
+    }
+
+    #[test]
+    fn to_packed_round_trips() {
+        for p in [
+            Pos(0, 0),
+            Pos(3, 7),
+            Pos(-3, 7),
+            Pos(3, -7),
+            Pos(-3, -7),
+            Pos(I::MIN, I::MAX),
+            Pos(I::MAX, I::MIN),
+        ] {
+            assert_eq!(Pos::from_packed(p.to_packed()), p);
+        }
+    }
+
+    #[test]
+    fn to_packed_agrees_with_lexicographic_order_including_negative_j() {
+        // Same `i`, negative `j`s: (-1, -1) < (-1, 0) lexicographically.
+        assert!(Pos(-1, -1).to_packed() < Pos(-1, 0).to_packed());
+        // Differing `i` still dominates the comparison regardless of `j`.
+        assert!(Pos(1, 100).to_packed() < Pos(2, -100).to_packed());
+        assert!(Pos(-2, 100).to_packed() < Pos(-1, -100).to_packed());
+    }
 }
@@ -0,0 +1,126 @@
+use std::cmp::Ordering;
+use std::cmp::Reverse;
+
+use crate::Pos;
+
+/// A total order on `Pos`, keyed first by anti-diagonal (`i+j`) and then by
+/// diagonal (`i-j`).
+///
+/// `Pos`'s own `PartialOrd` is a Pareto/domination order and is not a total
+/// order, so it can't be used directly as the key of a `BinaryHeap`. This
+/// wrapper gives A*/Dijkstra-style alignment searches a comparator that
+/// expands the frontier outward one anti-diagonal at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrderedPos(pub Pos);
+
+impl OrderedPos {
+    /// Wrap the key so that a `BinaryHeap<Reverse<OrderedPos>>` pops the
+    /// smallest anti-diagonal (and then smallest diagonal) first.
+    #[inline]
+    pub fn min_heap_key(self) -> Reverse<OrderedPos> {
+        Reverse(self)
+    }
+}
+
+impl PartialOrd for OrderedPos {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedPos {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.0.anti_diag(), self.0.diag()).cmp(&(other.0.anti_diag(), other.0.diag()))
+    }
+}
+
+/// A total order on `Pos`, keyed lexicographically by `(i, j)`.
+///
+/// Useful for banded/wavefront expansion where states should be popped
+/// row-by-row rather than by anti-diagonal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LexPos(pub Pos);
+
+impl LexPos {
+    /// Wrap the key so that a `BinaryHeap<Reverse<LexPos>>` pops the
+    /// lexicographically smallest `(i, j)` first.
+    #[inline]
+    pub fn min_heap_key(self) -> Reverse<LexPos> {
+        Reverse(self)
+    }
+}
+
+impl PartialOrd for LexPos {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LexPos {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.to_packed().cmp(&other.0.to_packed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BinaryHeap;
+
+    #[test]
+    fn ordered_pos_orders_by_anti_diag_then_diag() {
+        let mut v = vec![
+            OrderedPos(Pos(3, 0)),
+            OrderedPos(Pos(0, 0)),
+            OrderedPos(Pos(2, 1)),
+            OrderedPos(Pos(1, 2)),
+        ];
+        v.sort();
+        assert_eq!(
+            v,
+            vec![
+                OrderedPos(Pos(0, 0)),
+                OrderedPos(Pos(1, 2)),
+                OrderedPos(Pos(2, 1)),
+                OrderedPos(Pos(3, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn ordered_pos_min_heap_key_pops_smallest_anti_diag_first() {
+        let mut heap = BinaryHeap::new();
+        for p in [Pos(3, 0), Pos(0, 0), Pos(2, 1), Pos(1, 0)] {
+            heap.push(OrderedPos(p).min_heap_key());
+        }
+        let mut popped = Vec::new();
+        while let Some(Reverse(OrderedPos(p))) = heap.pop() {
+            popped.push(p);
+        }
+        assert_eq!(popped, vec![Pos(0, 0), Pos(1, 0), Pos(2, 1), Pos(3, 0)]);
+    }
+
+    #[test]
+    fn lex_pos_orders_lexicographically_including_negative_j() {
+        let mut v = vec![
+            LexPos(Pos(-1, 0)),
+            LexPos(Pos(-1, -1)),
+            LexPos(Pos(0, -5)),
+            LexPos(Pos(0, 5)),
+        ];
+        v.sort();
+        assert_eq!(
+            v,
+            vec![
+                LexPos(Pos(-1, -1)),
+                LexPos(Pos(-1, 0)),
+                LexPos(Pos(0, -5)),
+                LexPos(Pos(0, 5)),
+            ]
+        );
+    }
+}